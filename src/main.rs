@@ -1,4 +1,12 @@
-use std::{fmt, io, process, str::FromStr};
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, IsTerminal},
+    path::PathBuf,
+    process::ExitCode,
+    str::FromStr,
+};
+use clap::Parser;
 use colored::Colorize;
 
 /*
@@ -12,19 +20,18 @@ A -- Call run_app() --> B{"run_app()"};
     A -- Err(AppExitStatus::IoError) --> E[Print I/O error & Exit with status 1];
 
     subgraph " "
-        F[Display ""Temperature Conversion"" header] --> G{Get From Unit};
-        G -- Valid Input --> H{Determine Temp Value Prompt};
+        F[Display ""Temperature Conversion"" header] --> G{"Get From Temperature (value+unit, e.g. 32F)"};
+        G -- Valid Input --> G2{Get To Unit};
         G -- Invalid Input --> G;
         G -- "QUIT" typed --> Z{Return Err AppExitStatus::Quit};
 
-        H --> I{Get Original Value};
-        I -- Valid Input --> J[Perform Temperature Conversion];
-        I -- Invalid Input --> I;
-        I -- "QUIT" typed --> Z;
+        G2 -- Valid Input --> J[Perform Temperature Conversion];
+        G2 -- Invalid Input --> G2;
+        G2 -- "QUIT" typed --> Z;
 
         J --> K[Format Conversion Output];
         K --> L[Print Converted Temperature];
-        L --> M{"Return Ok(())"};
+        L --> F;
     end
 
     B -- run_app() calls --> F;
@@ -39,12 +46,12 @@ A -- Call run_app() --> B{"run_app()"};
 
 
     %%% Applying Styles to Nodes %%%
-    class A,Z,M startEndNode;
+    class A,Z startEndNode;
     class C successNode;
     class D quitNode;
     class E errorNode;
-    class F,H,J,K,L processNode;
-    class B,G,I decisionNode;
+    class F,J,K,L processNode;
+    class B,G,G2 decisionNode;
 MERMAID_DIAGRAM_END
 */
 
@@ -66,16 +73,34 @@ impl From<io::Error> for AppExitStatus {
 enum TemperatureUnit {
     Fahrenheit,
     Celcius,
+    Kelvin,
+    Rankine,
 }
 
+// A lightweight, displayable parse error shared by `TemperatureUnit` and
+// `Temperature`'s `FromStr` impls, so both can be parsed by `get_user_input`
+// and by clap's derive-based argument parsing.
+#[derive(Debug)]
+struct ParseError(&'static str);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 impl FromStr for TemperatureUnit {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_ref() {
             "c" | "celcius" => Ok(TemperatureUnit::Celcius),
             "f" | "fahrenheit" => Ok(TemperatureUnit::Fahrenheit),
-            _ => Err(()),
+            "k" | "kelvin" => Ok(TemperatureUnit::Kelvin),
+            "r" | "rankine" => Ok(TemperatureUnit::Rankine),
+            _ => Err(ParseError("invalid unit, expected C, F, K, or R")),
         }
     }
 }
@@ -85,6 +110,8 @@ impl fmt::Display for TemperatureUnit {
         match self {
             TemperatureUnit::Fahrenheit => write!(f, "F"),
             TemperatureUnit::Celcius => write!(f, "C"),
+            TemperatureUnit::Kelvin => write!(f, "K"),
+            TemperatureUnit::Rankine => write!(f, "R"),
         }
     }
 }
@@ -100,22 +127,82 @@ impl Temperature {
         Temperature { value, unit }
     }
 
-    fn to_celcius(&self) -> Temperature {
-        let celsius_value = (self.value - 32.0) * (5.0 / 9.0);
+    // Converts any unit into Celsius, the canonical unit every other
+    // conversion is routed through.
+    fn into_base(self) -> Temperature {
+        let celsius_value = match self.unit {
+            TemperatureUnit::Celcius => self.value,
+            TemperatureUnit::Fahrenheit => (self.value - 32.0) * (5.0 / 9.0),
+            TemperatureUnit::Kelvin => self.value - 273.15,
+            TemperatureUnit::Rankine => (self.value - 491.67) * (5.0 / 9.0),
+        };
         Temperature::new(celsius_value, TemperatureUnit::Celcius)
     }
 
-    fn to_fahrenheit(&self) -> Temperature {
-        let fahrenheit_value = (self.value * (9.0 / 5.0)) + 32.0;
-        Temperature::new(fahrenheit_value, TemperatureUnit::Fahrenheit)
+    // Converts a Celsius value (the canonical unit) into `target_unit`.
+    fn into_unit(self, target_unit: TemperatureUnit) -> Temperature {
+        let value = match target_unit {
+            TemperatureUnit::Celcius => self.value,
+            TemperatureUnit::Fahrenheit => (self.value * (9.0 / 5.0)) + 32.0,
+            TemperatureUnit::Kelvin => self.value + 273.15,
+            TemperatureUnit::Rankine => (self.value + 273.15) * (9.0 / 5.0),
+        };
+        Temperature::new(value, target_unit)
     }
 
     fn convert_to(&self, target_unit: TemperatureUnit) -> Temperature {
-        match (self.unit, target_unit) {
-            (TemperatureUnit::Fahrenheit, TemperatureUnit::Celcius) => self.to_celcius(),
-            (TemperatureUnit::Celcius, TemperatureUnit::Fahrenheit) => self.to_fahrenheit(),
-            _ => *self,
+        if self.unit == target_unit {
+            return *self;
+        }
+        self.into_base().into_unit(target_unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn celsius_converts_to_known_kelvin_and_rankine_fixed_points() {
+        let freezing = Temperature::new(0.0, TemperatureUnit::Celcius);
+        assert_eq!(freezing.convert_to(TemperatureUnit::Kelvin).value, 273.15);
+        assert!((freezing.convert_to(TemperatureUnit::Rankine).value - 491.67).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fahrenheit_and_celsius_cross_at_minus_forty() {
+        let fahrenheit = Temperature::new(-40.0, TemperatureUnit::Fahrenheit);
+        assert_eq!(fahrenheit.convert_to(TemperatureUnit::Celcius).value, -40.0);
+    }
+
+    #[test]
+    fn convert_to_same_unit_is_a_no_op() {
+        let kelvin = Temperature::new(300.0, TemperatureUnit::Kelvin);
+        assert_eq!(kelvin.convert_to(TemperatureUnit::Kelvin).value, 300.0);
+    }
+}
+
+// Parses a compact single-token temperature like "32F" or "-4.5c": the
+// trailing character is the unit, everything before it is the numeric value.
+impl FromStr for Temperature {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unit_char = s
+            .chars()
+            .last()
+            .ok_or(ParseError("expected a value followed by a unit, e.g. 32F"))?;
+        if !unit_char.is_alphabetic() {
+            return Err(ParseError("expected a trailing unit letter, e.g. 32F"));
         }
+
+        let unit = unit_char.to_string().parse::<TemperatureUnit>()?;
+        let value_str = &s[..s.len() - unit_char.len_utf8()];
+        let value: f64 = value_str
+            .parse()
+            .map_err(|_| ParseError("expected a numeric value before the unit"))?;
+
+        Ok(Temperature::new(value, unit))
     }
 }
 
@@ -126,40 +213,45 @@ fn format_conversion_output(
     converted_temp: Temperature,
 ) -> String {
     let converted_unit_char = converted_temp.unit.to_string();
-
-    match original_unit {
-        TemperatureUnit::Fahrenheit => {
-            if converted_temp.value.fract() != 0.0 {
-                format!(
-                    "\n({:.1}°{} - 32) * (5/9) = {:.1}°{}",
-                    original_value, original_unit, converted_temp.value, converted_unit_char
-                )
-            } else {
-                format!(
-                    "\n({:.0}°{} - 32) * (5/9) = {:.0}°{}",
-                    original_value,
-                    original_unit,
-                    converted_temp.value as u64,
-                    converted_unit_char
-                )
-            }
+    let whole_result = converted_temp.value.fract() == 0.0;
+
+    // The original two units round-trip through a single well-known formula,
+    // so keep printing it verbatim. Every other pair is routed through
+    // Celsius internally, so just show the resulting value.
+    let formula = match (original_unit, converted_temp.unit) {
+        (TemperatureUnit::Fahrenheit, TemperatureUnit::Celcius) => {
+            Some(format!("({}°{} - 32) * (5/9)", fmt_value(original_value), original_unit))
         }
-        TemperatureUnit::Celcius => {
-            if converted_temp.value.fract() != 0.0 {
-                format!(
-                    "\n({:.1}°{} * 9/5) + 32 = {:.1}°{}",
-                    original_value, original_unit, converted_temp.value, converted_unit_char
-                )
-            } else {
-                format!(
-                    "\n({:.0}°{} * 9/5) + 32 = {:.0}°{}",
-                    original_value,
-                    original_unit,
-                    converted_temp.value as u64,
-                    converted_unit_char
-                )
-            }
+        (TemperatureUnit::Celcius, TemperatureUnit::Fahrenheit) => {
+            Some(format!("({}°{} * 9/5) + 32", fmt_value(original_value), original_unit))
         }
+        _ => None,
+    };
+
+    match formula {
+        Some(formula) if whole_result => format!(
+            "\n{} = {:.0}°{}",
+            formula, converted_temp.value as i64, converted_unit_char
+        ),
+        Some(formula) => format!("\n{} = {:.1}°{}", formula, converted_temp.value, converted_unit_char),
+        None if whole_result => format!(
+            "\n{}°{} = {:.0}°{}",
+            fmt_value(original_value), original_unit, converted_temp.value as i64, converted_unit_char
+        ),
+        None => format!(
+            "\n{}°{} = {:.1}°{}",
+            fmt_value(original_value), original_unit, converted_temp.value, converted_unit_char
+        ),
+    }
+}
+
+// Renders a source value without a trailing ".0" for whole numbers, matching
+// the precision the formula lines have always used.
+fn fmt_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
     }
 }
 
@@ -195,75 +287,229 @@ where
     }
 }
 
-// --- Main Application Logic (Modified) ---
+// --- Pure Conversion Path (shared by the interactive and one-shot modes) ---
+fn convert_temperature(value: f64, from_unit: TemperatureUnit, to_unit: TemperatureUnit) -> Temperature {
+    Temperature::new(value, from_unit).convert_to(to_unit)
+}
 
-// This function now performs a single conversion cycle and returns its status.
-fn run_app() -> Result<(), AppExitStatus> {
-    let quit_prompt = |msg: &str| {
-        println!("\nType \"{}\" to end the program or\n{}", "QUIT".yellow().bold(), msg)
-    };
+// --- Command-Line Interface ---
+#[derive(Parser, Debug)]
+#[command(name = "tempconv", about = "Convert a temperature between units")]
+struct Cli {
+    /// The numeric temperature value to convert, e.g. 100 or -40
+    #[arg(allow_hyphen_values = true)]
+    value: Option<f64>,
+
+    /// The unit to convert from: C, F, K, or R
+    from_unit: Option<TemperatureUnit>,
+
+    /// The unit to convert to: C, F, K, or R
+    #[arg(long)]
+    to: Option<TemperatureUnit>,
+
+    /// Print only the converted numeric value, without the formula
+    /// (one-shot mode only; batch mode always prints formatted lines)
+    #[arg(short, long)]
+    quiet: bool,
+
+    /// Batch-convert every `value+unit` line (e.g. 72F) in this file to the
+    /// --to unit, instead of reading a single value and unit from the command
+    /// line; --to is required
+    #[arg(long)]
+    file: Option<PathBuf>,
+}
 
-    let msg_conversion: &'static str = "Enter C to convert to Fahrenheit or F to convert to Celsius";
-    let error_conversion: &'static str = "Invalid input. Please enter 'C' or 'F'.";
+#[cfg(test)]
+mod cli_tests {
+    use super::*;
 
-    println!("\n{}", "--- Temperature Conversion ---".cyan().bold());
+    #[test]
+    fn oneshot_accepts_a_negative_value() {
+        let cli = Cli::try_parse_from(["tempconv", "-40", "f", "--to", "c"]).unwrap();
+        assert_eq!(cli.value, Some(-40.0));
+        assert_eq!(cli.from_unit, Some(TemperatureUnit::Fahrenheit));
+        assert_eq!(cli.to, Some(TemperatureUnit::Celcius));
+    }
+}
 
-    // Get conversion unit, handling potential quit/errors
-    let from_unit: TemperatureUnit = get_user_input(
-        msg_conversion,
-        error_conversion,
-        &quit_prompt,
-    )?; // The `?` operator propagates AppExitStatus::Quit or AppExitStatus::IoError
+// Runs a single conversion from parsed CLI arguments and prints the result,
+// without any interactive prompts.
+fn oneshot(value: f64, from_unit: TemperatureUnit, to_unit: TemperatureUnit, quiet: bool) -> ExitCode {
+    let converted_temp = convert_temperature(value, from_unit, to_unit);
 
-    // Determine the prompt message and error message for temperature value
-    let (prompt_temp_value, error_temp_value) = match from_unit {
-        TemperatureUnit::Celcius => (
-            "Enter a number to convert Celsius to Fahrenheit.",
-            "Invalid temperature. Please enter a number."
-        ),
-        TemperatureUnit::Fahrenheit => (
-            "Enter a number to convert Fahrenheit to Celsius.",
-            "Invalid temperature. Please enter a number."
-        ),
+    if quiet {
+        println!("{}", converted_temp.value);
+    } else {
+        println!("{}", format_conversion_output(value, from_unit, converted_temp).trim_start());
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Batch-converts every `value+unit` line from `reader` to `to_unit`,
+// printing one formatted conversion per line. Lines that fail to parse are
+// skipped and reported, with their line numbers, once the stream is drained.
+fn batch_convert(reader: impl BufRead, to_unit: TemperatureUnit) -> ExitCode {
+    let mut failed_lines = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line_number = index + 1;
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                failed_lines.push(format!("{line_number}: {e}"));
+                continue;
+            }
+        };
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        match trimmed.parse::<Temperature>() {
+            Ok(original_temp) => {
+                let converted_temp = original_temp.convert_to(to_unit);
+                println!(
+                    "{}",
+                    format_conversion_output(original_temp.value, original_temp.unit, converted_temp)
+                        .trim_start()
+                );
+            }
+            Err(_) => failed_lines.push(format!("{line_number}: {trimmed}")),
+        }
+    }
+
+    if failed_lines.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!(
+            "{}",
+            format!("Could not parse {} line(s):", failed_lines.len()).red().bold()
+        );
+        for failure in &failed_lines {
+            eprintln!("  {failure}");
+        }
+        ExitCode::FAILURE
+    }
+}
+
+// --- Main Application Logic (Modified) ---
+
+// Loops through conversion cycles, one per iteration, until the user quits
+// or an I/O error occurs. Neither exit path falls through to the bottom of
+// the loop, so this function never returns `Ok(())` itself.
+fn run_app() -> Result<(), AppExitStatus> {
+    let quit_prompt = |msg: &str| {
+        println!("\nType \"{}\" to end the program or\n{}", "QUIT".yellow().bold(), msg)
     };
 
-    // Get temperature value, handling potential quit/errors
-    let original_value: f64 = get_user_input(
-        prompt_temp_value,
-        error_temp_value,
-        &quit_prompt,
-    )?; // The `?` operator propagates AppExitStatus::Quit or AppExitStatus::IoError
-
-    // Pure computation
-    let original_temp = Temperature::new(original_value, from_unit);
-    let converted_temp = original_temp.convert_to(match from_unit {
-        TemperatureUnit::Celcius => TemperatureUnit::Fahrenheit,
-        TemperatureUnit::Fahrenheit => TemperatureUnit::Celcius,
-    });
-
-    // Pure formatting, then side effect
-    let output_string = format_conversion_output(original_value, from_unit, converted_temp);
-    println!("{}", output_string.green().bold());
-
-    // If we reached this point, it means input was successful and conversion was printed.
-    // So, we return Ok(()) to signal normal completion to `main`.
-    Ok(())
+    let msg_from_temp: &'static str = "Enter the temperature to convert from, e.g. 32F or -4.5c";
+    let msg_to_unit: &'static str = "Enter the unit to convert to: C, F, K, or R";
+    let error_unit: &'static str = "Invalid input. Please enter 'C', 'F', 'K', or 'R'.";
+    let error_from_temp: &'static str =
+        "Invalid input. Please enter a number immediately followed by a unit, e.g. 32F.";
+
+    loop {
+        println!("\n{}", "--- Temperature Conversion ---".cyan().bold());
+
+        // Get the source value and unit as a single compact token, handling
+        // potential quit/errors
+        let original_temp: Temperature = get_user_input(
+            msg_from_temp,
+            error_from_temp,
+            &quit_prompt,
+        )?; // The `?` operator propagates AppExitStatus::Quit or AppExitStatus::IoError
+        let (original_value, from_unit) = (original_temp.value, original_temp.unit);
+
+        // Get the target unit, handling potential quit/errors
+        let to_unit: TemperatureUnit = get_user_input(
+            msg_to_unit,
+            error_unit,
+            &quit_prompt,
+        )?;
+
+        // Pure computation
+        let converted_temp = convert_temperature(original_value, from_unit, to_unit);
+
+        // Pure formatting, then side effect
+        let output_string = format_conversion_output(original_value, from_unit, converted_temp);
+        println!("{}", output_string.green().bold());
+
+        // Loop back to the top for another conversion instead of returning;
+        // only `QUIT` (Err(AppExitStatus::Quit)) or an I/O error leaves this loop.
+    }
 }
 
-fn main() {
-    // Call the main application logic.
+// Runs the original stdin-driven prompt flow.
+fn interactive() -> ExitCode {
     match run_app() {
         Ok(_) => {
-            // This arm is reached after a successful conversion.
+            // `run_app` never returns `Ok(())` (its loop only exits via `?`),
+            // but the arm is kept so a future clean loop exit has somewhere to go.
             println!("\nProgram finished normally.");
+            ExitCode::SUCCESS
         }
         Err(AppExitStatus::Quit) => {
             // User quit, the "Exiting program." message was already printed by get_user_input.
             // No additional message here.
+            ExitCode::SUCCESS
         }
         Err(AppExitStatus::IoError(e)) => {
             eprintln!("{}", format!("Program terminated due to I/O error: {}", e).red().bold());
-            process::exit(1); // Exit with a non-zero status for errors.
+            ExitCode::FAILURE
         }
     }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    // A `--file` always means batch mode, reading one token per line from it.
+    if let Some(path) = &cli.file {
+        let Some(to_unit) = cli.to else {
+            eprintln!("{}", "Batch mode requires --to <unit>.".red().bold());
+            return ExitCode::FAILURE;
+        };
+
+        return match File::open(path) {
+            Ok(file) => batch_convert(io::BufReader::new(file), to_unit),
+            Err(e) => {
+                eprintln!("{}", format!("Could not open {}: {}", path.display(), e).red().bold());
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    // A value, from-unit, and --to together mean a single scripted conversion.
+    // A value and/or from-unit without --to is an incomplete attempt at that,
+    // not a request to fall back to batch mode or the interactive prompts.
+    match (cli.value, cli.from_unit, cli.to) {
+        (Some(value), Some(from_unit), Some(to_unit)) => {
+            return oneshot(value, from_unit, to_unit, cli.quiet);
+        }
+        (None, None, None) | (None, None, Some(_)) => {}
+        _ => {
+            eprintln!(
+                "{}",
+                "A one-shot conversion needs a value, a from-unit, and --to <unit>.".red().bold()
+            );
+            return ExitCode::FAILURE;
+        }
+    }
+
+    // Piped input with nothing else specified means batch mode over stdin.
+    if !io::stdin().is_terminal() {
+        return match cli.to {
+            Some(to_unit) => batch_convert(io::stdin().lock(), to_unit),
+            None => {
+                eprintln!("{}", "Batch mode requires --to <unit>.".red().bold());
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    // No value or from-unit and a real terminal: keep the familiar interactive
+    // prompts. A lone --to or --quiet is not used in this mode.
+    interactive()
 }
\ No newline at end of file